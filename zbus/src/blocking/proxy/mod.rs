@@ -195,6 +195,18 @@ impl<'a> Proxy<'a> {
         block_on(self.inner().get_property(property_name))
     }
 
+    /// Get the property `property_name`, bypassing the cache.
+    ///
+    /// Always call the `Get` method of the `org.freedesktop.DBus.Properties` interface and update
+    /// the cache (if caching is enabled) with the fresh value.
+    pub fn get_property_uncached<T>(&self, property_name: &str) -> fdo::Result<T>
+    where
+        T: TryFrom<OwnedValue>,
+        T::Error: Into<Error>,
+    {
+        block_on(self.inner().get_property_uncached(property_name))
+    }
+
     /// Set the property `property_name`.
     ///
     /// Effectively, call the `Set` method of the `org.freedesktop.DBus.Properties` interface.
@@ -553,4 +565,50 @@ mod tests {
         // one we subscribed to.
         assert!(signal.args().unwrap().name() == well_known);
     }
+
+    #[test]
+    #[timeout(15000)]
+    fn uncached_property() {
+        struct TestIface {
+            count: u32,
+        }
+
+        #[crate::interface(name = "org.zbus.Test.UncachedProperty")]
+        impl TestIface {
+            #[zbus(property)]
+            fn count(&self) -> u32 {
+                self.count
+            }
+        }
+
+        let service_conn = blocking::connection::Builder::session()
+            .unwrap()
+            .name("org.zbus.Test.UncachedPropertyBlocking")
+            .unwrap()
+            .serve_at("/org/zbus/Test", TestIface { count: 0 })
+            .unwrap()
+            .build()
+            .unwrap();
+        let conn = Connection::session().unwrap();
+        let proxy = Proxy::new(
+            &conn,
+            "org.zbus.Test.UncachedPropertyBlocking",
+            "/org/zbus/Test",
+            "org.zbus.Test.UncachedProperty",
+        )
+        .unwrap();
+        assert_eq!(proxy.get_property::<u32>("Count").unwrap(), 0);
+
+        // Change the value without emitting `PropertiesChanged`.
+        service_conn
+            .object_server()
+            .interface::<_, TestIface>("/org/zbus/Test")
+            .unwrap()
+            .get_mut()
+            .count = 1;
+
+        assert_eq!(proxy.get_property::<u32>("Count").unwrap(), 0);
+        assert_eq!(proxy.get_property_uncached::<u32>("Count").unwrap(), 1);
+        assert_eq!(proxy.cached_property::<u32>("Count").unwrap(), Some(1));
+    }
 }
@@ -430,6 +430,7 @@ impl PropertiesCache {
 
             if let Some(entry) = values.get_mut(inval) {
                 entry.value = None;
+                entry.generation = entry.generation.wrapping_add(1);
                 entry.event.notify(usize::MAX);
             }
         }
@@ -456,10 +457,68 @@ impl PropertiesCache {
                 }
             };
             entry.value = Some(value);
+            entry.generation = entry.generation.wrapping_add(1);
             entry.event.notify(usize::MAX);
         }
     }
 
+    /// The current generation of the cache entry for `property_name`.
+    fn generation(&self, property_name: &str) -> u64 {
+        self.values
+            .read()
+            .expect("lock poisoned")
+            .get(property_name)
+            .map(|e| e.generation)
+            .unwrap_or_default()
+    }
+
+    /// Store a value fetched from the peer with a `Get` call.
+    ///
+    /// `generation` is the generation of the entry when the call was made. If the entry changed
+    /// since, the fetched value may be outdated and is dropped. Listeners are only notified if the
+    /// value actually differs from the cached one.
+    fn update_fetched(
+        &self,
+        uncached_properties: &HashSet<Str<'_>>,
+        property_name: &str,
+        value: &OwnedValue,
+        generation: u64,
+        interface: &InterfaceName<'_>,
+    ) {
+        if uncached_properties.contains(&Str::from(property_name)) {
+            debug!(
+                "Ignoring fetched value of uncached property `{}.{}`",
+                interface, property_name
+            );
+            return;
+        }
+
+        let mut values = self.values.write().expect("lock poisoned");
+        let entry = values.entry(property_name.to_string()).or_default();
+        if entry.generation != generation {
+            debug!(
+                "Property `{interface}.{property_name}` changed while being fetched, \
+                 ignoring fetched value"
+            );
+            return;
+        }
+        if entry.value.as_ref() == Some(value) {
+            return;
+        }
+
+        let value = match value.try_clone() {
+            Ok(value) => value,
+            Err(e) => {
+                debug!("Failed to clone fetched property `{interface}.{property_name}`: {e}");
+                return;
+            }
+        };
+        trace!("Property `{interface}.{property_name}` updated from fetched value");
+        entry.value = Some(value);
+        entry.generation = entry.generation.wrapping_add(1);
+        entry.event.notify(usize::MAX);
+    }
+
     /// Wait for the cache to be populated and return any error encountered during population
     pub(crate) async fn ready(&self) -> Result<()> {
         let listener = match &*self.caching_result.read().expect("lock poisoned") {
@@ -791,6 +850,40 @@ impl<'a> Proxy<'a> {
         value.try_into().map_err(Into::into)
     }
 
+    /// Get the property `property_name`, bypassing the cache.
+    ///
+    /// Unlike [`Proxy::get_property`], this always calls the `Get` method of the
+    /// `org.freedesktop.DBus.Properties` interface, even if the property is cached. If caching is
+    /// enabled, the cache is then updated with the fresh value, unless the property changed while
+    /// the call was in flight (in which case the cache already holds a newer value).
+    pub async fn get_property_uncached<T>(&self, property_name: &str) -> fdo::Result<T>
+    where
+        T: TryFrom<OwnedValue>,
+        T::Error: Into<Error>,
+    {
+        let cache = match self.inner.property_cache.as_ref().and_then(OnceLock::get) {
+            Some((cache, _)) if cache.ready().await.is_ok() => {
+                Some((cache, cache.generation(property_name)))
+            }
+            _ => None,
+        };
+
+        let value = self.get_proxy_property(property_name).await?;
+        if let Some((cache, generation)) = cache {
+            cache.update_fetched(
+                &self.inner.uncached_properties,
+                property_name,
+                &value,
+                generation,
+                &self.inner.interface,
+            );
+        }
+
+        value
+            .try_into()
+            .map_err(|e: T::Error| fdo::Error::from(e.into()))
+    }
+
     /// Set the property `property_name`.
     ///
     /// Effectively, call the `Set` method of the `org.freedesktop.DBus.Properties` interface.
@@ -1011,6 +1104,8 @@ impl<'a> Proxy<'a> {
 struct PropertyValue {
     value: Option<OwnedValue>,
     event: Event,
+    /// Bumped on every change to `value`, so in-flight fetches can detect that they're outdated.
+    generation: u64,
 }
 
 /// Flags to use with [`Proxy::call_with_flags`].
@@ -926,6 +926,65 @@ fn iface_and_proxy_unix_p2p() {
     block_on(iface_and_proxy_(true));
 }
 
+#[test]
+#[timeout(15000)]
+fn uncached_property() {
+    block_on(test_uncached_property()).unwrap();
+}
+
+async fn test_uncached_property() -> zbus::Result<()> {
+    let (next_tx, _next_rx) = channel(1);
+    let service_conn = connection::Builder::session()?
+        .name("org.freedesktop.MyService.UncachedProperty")?
+        .serve_at("/org/freedesktop/MyIface", MyIfaceImpl::new(next_tx))?
+        .build()
+        .await?;
+    let client_conn = Connection::session().await?;
+    let proxy = MyIfaceProxy::builder(&client_conn)
+        .destination("org.freedesktop.MyService.UncachedProperty")?
+        .build()
+        .await?;
+    assert_eq!(proxy.count().await?, 0);
+    assert_eq!(proxy.emits_changed_false().await?, 0);
+
+    // Change the values behind the proxy's back, without emitting `PropertiesChanged`.
+    let iface: InterfaceRef<MyIfaceImpl> = service_conn
+        .object_server()
+        .interface("/org/freedesktop/MyIface")
+        .await?;
+    {
+        let mut iface = iface.get_mut().await;
+        iface.count = 5;
+        iface.emits_changed_false = 7;
+    }
+
+    // The cache is now stale.
+    assert_eq!(proxy.count().await?, 0);
+    assert_eq!(proxy.cached_count()?, Some(0));
+
+    assert_eq!(
+        proxy.inner().get_property_uncached::<u32>("Count").await?,
+        5
+    );
+    assert_eq!(proxy.cached_count()?, Some(5));
+    assert_eq!(proxy.count().await?, 5);
+
+    // Uncached properties must stay out of the cache.
+    assert_eq!(
+        proxy
+            .inner()
+            .get_property_uncached::<u32>("EmitsChangedFalse")
+            .await?,
+        7
+    );
+    assert_eq!(
+        proxy.inner().cached_property::<u32>("EmitsChangedFalse")?,
+        None
+    );
+
+    Ok(())
+}
+
 #[instrument]
 async fn iface_and_proxy_(#[allow(unused)] p2p: bool) {
     let event = event_listener::Event::new();